@@ -1,23 +1,133 @@
+use std::io::{self, Stdout};
+use std::panic;
+use std::time::{Duration, Instant};
+
 use color_eyre::eyre::Result;
-use crossterm::event::{self, KeyCode, KeyEvent, KeyEventKind};
+use crossterm::event::{
+    self, DisableMouseCapture, EnableMouseCapture, KeyCode, KeyEvent, KeyEventKind, MouseButton,
+    MouseEvent, MouseEventKind,
+};
+use crossterm::execute;
+use crossterm::terminal::{
+    disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
+};
 use ratatui::{prelude::*, widgets::*};
 
+/// A terminal backed by crossterm over stdout, as used throughout the app.
+type DefaultTerminal = Terminal<CrosstermBackend<Stdout>>;
+
+/// A single entry in the choices list. Entries can be a plain line of text or a
+/// richer multi-line widget such as a combatant with a health bar, so a single
+/// list can mix the two.
+enum Choice {
+    Text(String),
+    Combatant {
+        name: String,
+        hp: u16,
+        max_hp: u16,
+    },
+}
+
+impl Choice {
+    // The text shown when this entry is confirmed.
+    fn label(&self) -> String {
+        match self {
+            Choice::Text(text) => text.clone(),
+            Choice::Combatant { name, .. } => name.clone(),
+        }
+    }
+
+    // How many rows this entry occupies in the list.
+    fn height(&self) -> u16 {
+        match self {
+            Choice::Text(_) => 1,
+            Choice::Combatant { .. } => 2,
+        }
+    }
+
+    // Draw the entry into its sub-rect of the choices pane.
+    fn render(&self, area: Rect, buf: &mut Buffer) {
+        match self {
+            Choice::Text(text) => {
+                Paragraph::new(Span::raw(text)).render(area, buf);
+            }
+            Choice::Combatant { name, hp, max_hp } => {
+                let rows = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([Constraint::Length(1), Constraint::Length(1)])
+                    .split(area);
+                Paragraph::new(Span::raw(name)).render(rows[0], buf);
+                let ratio = if *max_hp == 0 {
+                    0.0
+                } else {
+                    f64::from(*hp) / f64::from(*max_hp)
+                };
+                Gauge::default()
+                    .gauge_style(Style::default().fg(Color::Red))
+                    .ratio(ratio)
+                    .label(format!("{hp}/{max_hp}"))
+                    .render(rows[1], buf);
+            }
+        }
+    }
+}
+
+// Enable raw mode, switch to the alternate screen, and install a panic hook
+// that restores the terminal before the previously installed hook prints the
+// message.
+fn init() -> Result<DefaultTerminal> {
+    enable_raw_mode()?;
+    execute!(io::stdout(), EnterAlternateScreen, EnableMouseCapture)?;
+
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        // Best effort: ignore errors while unwinding so the original panic
+        // still surfaces.
+        let _ = restore();
+        hook(info);
+    }));
+
+    let backend = CrosstermBackend::new(io::stdout());
+    Ok(Terminal::new(backend)?)
+}
+
+// Undo everything `init` set up, leaving the terminal in its original state.
+fn restore() -> Result<()> {
+    execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture)?;
+    disable_raw_mode()?;
+    Ok(())
+}
+
 #[derive(Default)]
 struct App {
-    selected_index: usize,
+    state: ListState,         // Tracks the highlighted row and scroll offset
+    selected: Vec<usize>,     // Toggled-on rows in the multi-select set
     selected_item: String,    // Store the selected item
+    selected_items: Vec<String>, // Store every toggled-on item on confirm
     selected_message: String, // Store the message related to the selected item
-    choices: Vec<String>,
+    choices: Vec<Choice>,
+    visible_items: usize,     // Count of whole items visible in the choices pane, cached each draw
+    frame: usize,             // Monotonic animation frame counter
+    progress: f64,            // Charge meter, 0.0..=1.0
+    tick_rate: Duration,      // How often `on_tick` advances the animation
+    list_area: Rect,         // Choices pane rect, cached each draw for mouse hit-testing
 }
 
 impl App {
     // Initialize the app
-    pub fn new(choices: Vec<String>) -> Self {
+    pub fn new(choices: Vec<Choice>) -> Self {
         App {
-            selected_index: 0,
+            state: ListState::default().with_selected(Some(0)),
+            selected: Vec::new(),
             selected_item: String::new(),
+            selected_items: Vec::new(),
             selected_message: String::new(),
             choices,
+            visible_items: 0,
+            frame: 0,
+            progress: 0.0,
+            tick_rate: Duration::from_millis(250),
+            list_area: Rect::default(),
         }
     }
 
@@ -28,7 +138,22 @@ impl App {
             "You selected Choice 2!",
             "You selected Choice 3!",
         ];
-        self.selected_message = messages[self.selected_index].to_string();
+        let index = self.state.selected().unwrap_or(0);
+        self.selected_message = messages
+            .get(index)
+            .copied()
+            .unwrap_or("You made a selection!")
+            .to_string();
+    }
+
+    // Advance animation state by one tick: bump the frame counter and refill
+    // the charge meter, wrapping back to empty once full.
+    pub fn on_tick(&mut self) {
+        self.frame = self.frame.wrapping_add(1);
+        self.progress += 0.05;
+        if self.progress > 1.0 {
+            self.progress = 0.0;
+        }
     }
 }
 
@@ -50,6 +175,26 @@ impl InputHandler {
                 app.select_previous();
                 false
             }
+            KeyCode::Home => {
+                app.select_first();
+                false
+            }
+            KeyCode::End => {
+                app.select_last();
+                false
+            }
+            KeyCode::PageDown => {
+                app.select_page_down();
+                false
+            }
+            KeyCode::PageUp => {
+                app.select_page_up();
+                false
+            }
+            KeyCode::Char(' ') => {
+                app.toggle_selection();
+                false
+            }
             KeyCode::Enter => {
                 app.confirm_selection();
                 false
@@ -57,28 +202,135 @@ impl InputHandler {
             _ => false,
         }
     }
+
+    // Handle mouse events: left-click highlights the row under the cursor (and
+    // confirms when it is already highlighted), the wheel scrolls the list.
+    pub fn handle_mouse_event(app: &mut App, mouse: MouseEvent) -> bool {
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                if let Some(index) = app.choice_at_row(mouse.row) {
+                    if app.state.selected() == Some(index) {
+                        app.confirm_selection();
+                    } else {
+                        app.state.select(Some(index));
+                        app.update_message();
+                    }
+                }
+                false
+            }
+            MouseEventKind::ScrollDown => {
+                app.select_next();
+                false
+            }
+            MouseEventKind::ScrollUp => {
+                app.select_previous();
+                false
+            }
+            _ => false,
+        }
+    }
 }
 
 impl App {
     // Handle the logic for selecting the next item
     pub fn select_next(&mut self) {
-        self.selected_index = (self.selected_index + 1) % self.choices.len();
+        let index = match self.state.selected() {
+            Some(i) => (i + 1) % self.choices.len(),
+            None => 0,
+        };
+        self.state.select(Some(index));
         self.update_message();
     }
 
     // Handle the logic for selecting the previous item
     pub fn select_previous(&mut self) {
-        self.selected_index = if self.selected_index == 0 {
-            self.choices.len() - 1
-        } else {
-            self.selected_index - 1
+        let index = match self.state.selected() {
+            Some(0) | None => self.choices.len() - 1,
+            Some(i) => i - 1,
         };
+        self.state.select(Some(index));
+        self.update_message();
+    }
+
+    // Jump to the first item
+    pub fn select_first(&mut self) {
+        self.state.select(Some(0));
+        self.update_message();
+    }
+
+    // Jump to the last item
+    pub fn select_last(&mut self) {
+        self.state.select(Some(self.choices.len() - 1));
+        self.update_message();
+    }
+
+    // Move down by a page, clamping at the last item instead of wrapping
+    pub fn select_page_down(&mut self) {
+        let page = self.visible_items.max(1);
+        let last = self.choices.len() - 1;
+        let index = self.state.selected().unwrap_or(0);
+        self.state.select(Some((index + page).min(last)));
+        self.update_message();
+    }
+
+    // Move up by a page, clamping at the first item instead of wrapping
+    pub fn select_page_up(&mut self) {
+        let page = self.visible_items.max(1);
+        let index = self.state.selected().unwrap_or(0);
+        self.state.select(Some(index.saturating_sub(page)));
         self.update_message();
     }
 
-    // Confirm the selection and update the selected item
+    // Map a screen row inside the choices pane to a choice index. Starts from
+    // the scrolled-to offset and stops at the viewport edge, mirroring exactly
+    // what `render_choices` draws so a click always resolves to the row under
+    // the cursor.
+    pub fn choice_at_row(&self, row: u16) -> Option<usize> {
+        let top = self.list_area.y + 1; // Skip the pane's top border.
+        let bottom = top + self.list_area.height.saturating_sub(2);
+        if row < top || row >= bottom {
+            return None;
+        }
+        let mut y = top;
+        for (i, choice) in self.choices.iter().enumerate().skip(self.state.offset()) {
+            let height = choice.height();
+            if y + height > bottom {
+                break; // Past the viewport — not a drawn row.
+            }
+            if row >= y && row < y + height {
+                return Some(i);
+            }
+            y += height;
+        }
+        None
+    }
+
+    // Toggle the highlighted row in or out of the multi-select set
+    pub fn toggle_selection(&mut self) {
+        let index = self.state.selected().unwrap_or(0);
+        if let Some(pos) = self.selected.iter().position(|&i| i == index) {
+            self.selected.remove(pos);
+        } else {
+            self.selected.push(index);
+        }
+    }
+
+    // Confirm the selection and update the selected item(s)
     pub fn confirm_selection(&mut self) {
-        self.selected_item = self.choices[self.selected_index].clone();
+        let index = self.state.selected().unwrap_or(0);
+        self.selected_item = self.choices[index].label();
+        // If nothing has been toggled, fall back to the highlighted row so
+        // single-select callers still get exactly one entry.
+        let mut indices: Vec<usize> = if self.selected.is_empty() {
+            vec![index]
+        } else {
+            self.selected.clone()
+        };
+        indices.sort_unstable();
+        self.selected_items = indices
+            .into_iter()
+            .map(|i| self.choices[i].label())
+            .collect();
         self.update_message();
     }
 }
@@ -87,7 +339,7 @@ struct AppPresenter;
 
 impl AppPresenter {
     // Render the app's state to the terminal
-    pub fn render(app: &App, terminal: &mut Terminal<CrosstermBackend<std::io::Stdout>>) {
+    pub fn render(app: &mut App, terminal: &mut DefaultTerminal) {
         terminal
             .draw(|f| {
                 let size = f.area();
@@ -97,15 +349,24 @@ impl AppPresenter {
                         Constraint::Percentage(60),
                         Constraint::Percentage(20),
                         Constraint::Percentage(20),
+                        Constraint::Length(3),
                     ])
                     .split(size);
 
+                // Cache the pane rect for mouse hit-testing; `render_choices`
+                // updates the scroll offset and visible-item count.
+                app.list_area = layout[0];
+
                 // Rendering list of choices
-                let list = AppPresenter::render_choices(app);
-                f.render_widget(list, layout[0]);
+                AppPresenter::render_choices(app, layout[0], f.buffer_mut());
 
-                // Rendering selected item
-                let selected_text = format!("Selected: {}", app.selected_item);
+                // Rendering selected item(s): show the whole committed set so
+                // multi-select results are visible, not just the last highlight.
+                let selected_text = if app.selected_items.is_empty() {
+                    format!("Selected: {}", app.selected_item)
+                } else {
+                    format!("Selected: {}", app.selected_items.join(", "))
+                };
                 let selected_paragraph = Paragraph::new(Span::raw(selected_text)).block(
                     Block::default()
                         .borders(Borders::ALL)
@@ -117,61 +378,165 @@ impl AppPresenter {
                 let message_paragraph = Paragraph::new(Span::raw(&app.selected_message))
                     .block(Block::default().borders(Borders::ALL).title("Message"));
                 f.render_widget(message_paragraph, layout[2]);
+
+                // Rendering the animated charge meter
+                let charge = AppPresenter::render_charge(app);
+                f.render_widget(charge, layout[3]);
             })
             .unwrap();
     }
 
-    // Render the list of choices
-    fn render_choices(app: &App) -> List {
-        let items: Vec<ListItem> = app
-            .choices
-            .iter()
-            .enumerate()
-            .map(|(i, choice)| {
-                let style = if i == app.selected_index {
-                    Style::default().fg(Color::Yellow)
-                } else {
-                    Style::default()
-                };
-                ListItem::new(Span::raw(choice)).style(style)
-            })
-            .collect();
+    // Build the charge gauge, colouring the fill along a green->yellow->red
+    // ramp as it advances each tick.
+    fn render_charge(app: &App) -> Gauge<'_> {
+        let color = if app.progress < 0.5 {
+            Color::Green
+        } else if app.progress < 0.8 {
+            Color::Yellow
+        } else {
+            Color::Red
+        };
+        // A frame-indexed spinner in the title shows the animation is live even
+        // while the meter sits at the same fill between ticks.
+        const SPINNER: [char; 4] = ['|', '/', '-', '\\'];
+        let spinner = SPINNER[app.frame % SPINNER.len()];
+        Gauge::default()
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!("Charge {spinner}")),
+            )
+            .gauge_style(Style::default().fg(color))
+            .ratio(app.progress)
+    }
+
+    // Render the list of choices, laying each entry out top-to-bottom and
+    // honoring its requested height. The highlighted entry gets an accent
+    // border; toggled-on entries are prefixed with a checkmark.
+    fn render_choices(app: &mut App, area: Rect, buf: &mut Buffer) {
+        let block = Block::default().borders(Borders::ALL).title("Choices");
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let highlighted = app.state.selected();
+        let selected = highlighted.unwrap_or(0);
 
-        List::new(items)
-            .block(Block::default().borders(Borders::ALL).title("Choices"))
-            .highlight_style(Style::default().fg(Color::Yellow))
+        // Scroll the viewport so the selected row stays visible. Offset is the
+        // index of the first drawn item; grow it (from the top) until the run
+        // of items offset..=selected fits within the pane's height.
+        let mut offset = app.state.offset().min(selected);
+        loop {
+            let used: u16 = app.choices[offset..=selected]
+                .iter()
+                .map(Choice::height)
+                .sum();
+            if used <= inner.height || offset == selected {
+                break;
+            }
+            offset += 1;
+        }
+        *app.state.offset_mut() = offset;
+
+        let mut visible = 0usize;
+        let mut y = inner.y;
+        for (i, choice) in app.choices.iter().enumerate().skip(offset) {
+            let height = choice.height();
+            if y + height > inner.y + inner.height {
+                break; // No more room in the viewport.
+            }
+            visible += 1;
+            let row = Rect::new(inner.x, y, inner.width, height);
+
+            // The highlighted row gets an accent left border; its content is
+            // drawn inside so the border never overwrites a glyph.
+            let content = if highlighted == Some(i) {
+                let accent = Block::default()
+                    .borders(Borders::LEFT)
+                    .border_style(Style::default().fg(Color::Yellow));
+                let inner_row = accent.inner(row);
+                accent.render(row, buf);
+                inner_row
+            } else {
+                row
+            };
+
+            // Reserve a two-column gutter for the cursor / toggle marker.
+            let marker = if app.selected.contains(&i) {
+                "\u{2713} "
+            } else if highlighted == Some(i) {
+                "> "
+            } else {
+                "  "
+            };
+            let gutter = Rect::new(content.x, content.y, 2.min(content.width), content.height);
+            Paragraph::new(Span::raw(marker)).render(gutter, buf);
+
+            let body = Rect::new(
+                content.x + gutter.width,
+                content.y,
+                content.width.saturating_sub(gutter.width),
+                content.height,
+            );
+            choice.render(body, buf);
+
+            y += height;
+        }
+
+        // Cache how many whole items actually fit so paging jumps by items, not
+        // raw terminal rows.
+        app.visible_items = visible.max(1);
     }
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
     color_eyre::install()?;
-    let stdout = std::io::stdout();
-    let backend = CrosstermBackend::new(stdout);
-    let mut terminal = Terminal::new(backend)?;
+    let mut terminal = init()?;
 
     // Initialize App
     let mut app = App::new(vec![
-        "Choice 1".to_string(),
-        "Choice 2".to_string(),
-        "Choice 3".to_string(),
+        Choice::Text("Choice 1".to_string()),
+        Choice::Combatant {
+            name: "Goblin".to_string(),
+            hp: 18,
+            max_hp: 30,
+        },
+        Choice::Text("Choice 3".to_string()),
     ]);
     terminal.clear()?;
 
+    let tick_rate = app.tick_rate;
+    let mut last_tick = Instant::now();
     loop {
         // Drawing the app
-        AppPresenter::render(&app, &mut terminal);
+        AppPresenter::render(&mut app, &mut terminal);
 
-        // Handling key events
-        if event::poll(std::time::Duration::from_millis(500))? {
-            if let event::Event::Key(key_event) = event::read()? {
+        // Wait for input for at most the time left in the current tick.
+        let timeout = tick_rate.saturating_sub(last_tick.elapsed());
+        if event::poll(timeout)? {
+            match event::read()? {
                 // If Esc is pressed, return to exit the loop
-                if InputHandler::handle_key_event(&mut app, key_event) {
-                    break; // Exit the loop
+                event::Event::Key(key_event) => {
+                    if InputHandler::handle_key_event(&mut app, key_event) {
+                        break; // Exit the loop
+                    }
                 }
+                event::Event::Mouse(mouse_event) => {
+                    if InputHandler::handle_mouse_event(&mut app, mouse_event) {
+                        break;
+                    }
+                }
+                _ => {}
             }
         }
+
+        // Advance animation state whenever a full tick has elapsed.
+        if last_tick.elapsed() >= tick_rate {
+            app.on_tick();
+            last_tick = Instant::now();
+        }
     }
 
+    restore()?;
     Ok(())
 }